@@ -0,0 +1,404 @@
+//! Loading real transaction/block access lists (EIP-2930 style JSON) and
+//! turning them into a [`WitnessComparison`] via [`StateAccessTracker`],
+//! so the tool can be pointed at mainnet data instead of only the
+//! hard-coded synthetic scenarios in `main`.
+
+use crate::{code_chunk_count, Address, Config, StateAccessTracker, StorageKey, WitnessComparison};
+use std::fmt;
+use std::fs;
+
+/// EIP-170 caps deployed contract code at this many bytes. `codeLenBytes`
+/// beyond this can't correspond to a real contract, so it's rejected rather
+/// than trusted — an access list is untrusted input, and a bogus value here
+/// would otherwise balloon into billions of `access_code_chunk` calls.
+const EIP170_MAX_CONTRACT_SIZE_BYTES: usize = 24_576;
+
+/// One entry of an EIP-2930 access list: an address plus the storage slots
+/// touched under it. `code_len_bytes` is not part of the EIP-2930 spec but
+/// is accepted as an optional extra field so code-witness size can be
+/// estimated too.
+#[derive(Debug, Clone)]
+pub struct AccessListEntry {
+    pub address: Address,
+    pub storage_keys: Vec<StorageKey>,
+    pub code_len_bytes: Option<usize>,
+}
+
+#[derive(Debug)]
+pub enum AccessListError {
+    Io(std::io::Error),
+    Json(JsonError),
+    Schema(String),
+}
+
+impl fmt::Display for AccessListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessListError::Io(err) => write!(f, "could not read access list file: {err}"),
+            AccessListError::Json(err) => write!(f, "invalid JSON: {err}"),
+            AccessListError::Schema(msg) => write!(f, "invalid access list: {msg}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for AccessListError {
+    fn from(err: std::io::Error) -> Self {
+        AccessListError::Io(err)
+    }
+}
+
+impl From<JsonError> for AccessListError {
+    fn from(err: JsonError) -> Self {
+        AccessListError::Json(err)
+    }
+}
+
+/// Reads and parses an EIP-2930 style access list JSON file, e.g.:
+///
+/// ```json
+/// [
+///   {"address": "0xabc...", "storageKeys": ["0x01", "0x02"]},
+///   {"address": "0xdef...", "storageKeys": [], "codeLenBytes": 12000}
+/// ]
+/// ```
+pub fn load_access_list(path: &str) -> Result<Vec<AccessListEntry>, AccessListError> {
+    let contents = fs::read_to_string(path)?;
+    parse_access_list(&contents)
+}
+
+pub fn parse_access_list(json: &str) -> Result<Vec<AccessListEntry>, AccessListError> {
+    let value = parse_json(json)?;
+    let entries = match value {
+        JsonValue::Array(entries) => entries,
+        _ => return Err(AccessListError::Schema("expected a top-level JSON array".to_string())),
+    };
+
+    entries.into_iter().map(parse_access_list_entry).collect()
+}
+
+fn parse_access_list_entry(value: JsonValue) -> Result<AccessListEntry, AccessListError> {
+    let fields = match value {
+        JsonValue::Object(fields) => fields,
+        _ => return Err(AccessListError::Schema("expected each access list entry to be an object".to_string())),
+    };
+
+    let mut address = None;
+    let mut storage_keys = Vec::new();
+    let mut code_len_bytes = None;
+
+    for (key, val) in fields {
+        match key.as_str() {
+            "address" => {
+                address = Some(expect_string(val, "address")?);
+            }
+            "storageKeys" => {
+                let JsonValue::Array(keys) = val else {
+                    return Err(AccessListError::Schema("expected storageKeys to be an array".to_string()));
+                };
+                for key_value in keys {
+                    storage_keys.push(expect_string(key_value, "storageKeys[]")?);
+                }
+            }
+            "codeLenBytes" => {
+                let len = expect_usize(val, "codeLenBytes")?;
+                if len > EIP170_MAX_CONTRACT_SIZE_BYTES {
+                    return Err(AccessListError::Schema(format!(
+                        "codeLenBytes {len} exceeds the EIP-170 max contract size of {EIP170_MAX_CONTRACT_SIZE_BYTES} bytes"
+                    )));
+                }
+                code_len_bytes = Some(len);
+            }
+            _ => {} // ignore unknown fields for forwards-compatibility
+        }
+    }
+
+    let address = address.ok_or_else(|| AccessListError::Schema("entry missing \"address\"".to_string()))?;
+
+    Ok(AccessListEntry { address, storage_keys, code_len_bytes })
+}
+
+fn expect_string(value: JsonValue, field: &str) -> Result<String, AccessListError> {
+    match value {
+        JsonValue::String(s) => Ok(s),
+        _ => Err(AccessListError::Schema(format!("expected {field} to be a string"))),
+    }
+}
+
+fn expect_usize(value: JsonValue, field: &str) -> Result<usize, AccessListError> {
+    match value {
+        JsonValue::Number(n) if n.is_finite() && n >= 0.0 => Ok(n as usize),
+        _ => Err(AccessListError::Schema(format!("expected {field} to be a finite, non-negative number"))),
+    }
+}
+
+/// Feeds an access list through a [`StateAccessTracker`] and produces a
+/// [`WitnessComparison`] reflecting the real witness size of the accesses
+/// it describes. When `code_len_bytes` is present for an address, every
+/// chunk of that code is treated as touched, since an access list alone
+/// doesn't say which chunks execution actually read.
+pub fn witness_comparison_from_access_list(entries: &[AccessListEntry], config: &Config) -> WitnessComparison {
+    let mut tracker = StateAccessTracker::new();
+
+    for entry in entries {
+        tracker.access_account(&entry.address);
+        for slot in &entry.storage_keys {
+            tracker.access_storage(&entry.address, slot);
+        }
+        if let Some(code_len_bytes) = entry.code_len_bytes {
+            tracker.access_code(&entry.address);
+            for chunk in 0..code_chunk_count(code_len_bytes) {
+                tracker.access_code_chunk(&entry.address, chunk);
+            }
+        }
+    }
+
+    WitnessComparison::from_tracker(format!("Access List ({} addresses)", entries.len()), &tracker, config)
+}
+
+// --- A small hand-rolled JSON parser -----------------------------------
+//
+// The access list format needed here is a plain array of flat objects, so
+// rather than pull in a JSON crate (this project has no dependencies) we
+// parse just enough JSON to cover it: null/bool/number/string/array/object.
+
+#[derive(Debug, Clone)]
+enum JsonValue {
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+#[derive(Debug)]
+pub struct JsonError(String);
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+struct JsonParser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+    source: &'a str,
+}
+
+fn parse_json(input: &str) -> Result<JsonValue, JsonError> {
+    let mut parser = JsonParser { chars: input.char_indices().peekable(), source: input };
+    parser.skip_whitespace();
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+    if let Some(&(_, c)) = parser.chars.peek() {
+        return Err(JsonError(format!("unexpected trailing character '{c}' after top-level value")));
+    }
+    Ok(value)
+}
+
+impl<'a> JsonParser<'a> {
+    fn skip_whitespace(&mut self) {
+        while let Some(&(_, c)) = self.chars.peek() {
+            if c.is_whitespace() {
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek_char(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn expect_char(&mut self, expected: char) -> Result<(), JsonError> {
+        match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((_, c)) => Err(JsonError(format!("expected '{expected}', found '{c}'"))),
+            None => Err(JsonError(format!("expected '{expected}', found end of input"))),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue, JsonError> {
+        self.skip_whitespace();
+        match self.peek_char() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => Ok(JsonValue::String(self.parse_string()?)),
+            Some('n') => self.parse_literal("null", JsonValue::Object(Vec::new())),
+            Some('t') => self.parse_literal("true", JsonValue::Number(1.0)),
+            Some('f') => self.parse_literal("false", JsonValue::Number(0.0)),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(c) => Err(JsonError(format!("unexpected character '{c}'"))),
+            None => Err(JsonError("unexpected end of input".to_string())),
+        }
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, JsonError> {
+        for expected in literal.chars() {
+            self.expect_char(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect_char('{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.peek_char() == Some('}') {
+            self.chars.next();
+            return Ok(JsonValue::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect_char(':')?;
+            let value = self.parse_value()?;
+            fields.push((key, value));
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, '}')) => break,
+                Some((_, c)) => return Err(JsonError(format!("expected ',' or '}}', found '{c}'"))),
+                None => return Err(JsonError("unterminated object".to_string())),
+            }
+        }
+        Ok(JsonValue::Object(fields))
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect_char('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek_char() == Some(']') {
+            self.chars.next();
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            let value = self.parse_value()?;
+            items.push(value);
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, ']')) => break,
+                Some((_, c)) => return Err(JsonError(format!("expected ',' or ']', found '{c}'"))),
+                None => return Err(JsonError("unterminated array".to_string())),
+            }
+        }
+        Ok(JsonValue::Array(items))
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.expect_char('"')?;
+        let mut result = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => break,
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, 'n')) => result.push('\n'),
+                    Some((_, 't')) => result.push('\t'),
+                    Some((_, '"')) => result.push('"'),
+                    Some((_, '\\')) => result.push('\\'),
+                    Some((_, '/')) => result.push('/'),
+                    Some((_, other)) => result.push(other),
+                    None => return Err(JsonError("unterminated escape sequence".to_string())),
+                },
+                Some((_, c)) => result.push(c),
+                None => return Err(JsonError("unterminated string".to_string())),
+            }
+        }
+        Ok(result)
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue, JsonError> {
+        let start = self.chars.peek().map(|&(i, _)| i).unwrap_or(self.source.len());
+        let mut end = start;
+        while let Some(&(i, c)) = self.chars.peek() {
+            if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' {
+                end = i + c.len_utf8();
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        self.source[start..end]
+            .parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|_| JsonError(format!("invalid number literal '{}'", &self.source[start..end])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_storage_keys_are_deduped_as_warm() {
+        let entries = parse_access_list(
+            r#"[{"address": "0xabc", "storageKeys": ["0x1", "0x1", "0x2"]}]"#,
+        )
+        .unwrap();
+        let comparison = witness_comparison_from_access_list(&entries, &Config::default());
+        let json = comparison.to_json();
+        assert!(json.contains("\"cold_accesses\":3"), "{json}");
+        assert!(json.contains("\"warm_accesses\":1"), "{json}");
+    }
+
+    #[test]
+    fn missing_address_is_a_schema_error() {
+        let err = parse_access_list(r#"[{"storageKeys": []}]"#).unwrap_err();
+        assert!(matches!(err, AccessListError::Schema(_)), "{err:?}");
+    }
+
+    #[test]
+    fn code_len_bytes_present_vs_absent() {
+        let entries = parse_access_list(
+            r#"[{"address": "0xabc"}, {"address": "0xdef", "codeLenBytes": 1000}]"#,
+        )
+        .unwrap();
+        assert_eq!(entries[0].code_len_bytes, None);
+        assert_eq!(entries[1].code_len_bytes, Some(1000));
+    }
+
+    #[test]
+    fn code_len_bytes_over_eip170_cap_is_rejected() {
+        let err = parse_access_list(r#"[{"address": "0xabc", "codeLenBytes": 100000000000}]"#).unwrap_err();
+        assert!(matches!(err, AccessListError::Schema(_)), "{err:?}");
+    }
+
+    #[test]
+    fn code_len_bytes_non_finite_is_rejected() {
+        let err = parse_access_list(r#"[{"address": "0xabc", "codeLenBytes": 1e400}]"#).unwrap_err();
+        assert!(matches!(err, AccessListError::Schema(_)), "{err:?}");
+    }
+
+    #[test]
+    fn empty_input_is_rejected_not_panicking() {
+        assert!(parse_access_list("").is_err());
+    }
+
+    #[test]
+    fn malformed_json_is_rejected() {
+        assert!(parse_access_list("not json").is_err());
+    }
+
+    #[test]
+    fn non_array_top_level_is_a_schema_error() {
+        let err = parse_access_list(r#"{"address": "0xabc"}"#).unwrap_err();
+        assert!(matches!(err, AccessListError::Schema(_)), "{err:?}");
+    }
+
+    #[test]
+    fn trailing_content_after_top_level_value_is_rejected() {
+        assert!(parse_access_list(r#"[]garbage"#).is_err());
+        assert!(parse_access_list(r#"[]{}"#).is_err());
+    }
+
+    #[test]
+    fn chunk_count_math_matches_eip4762_31_byte_chunks() {
+        assert_eq!(code_chunk_count(0), 0);
+        assert_eq!(code_chunk_count(1), 1);
+        assert_eq!(code_chunk_count(31), 1);
+        assert_eq!(code_chunk_count(32), 2);
+        assert_eq!(code_chunk_count(EIP170_MAX_CONTRACT_SIZE_BYTES), 793);
+    }
+}
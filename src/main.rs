@@ -1,27 +1,259 @@
-// Constants based on Ethereum research
+use std::collections::HashSet;
+
+mod access_list;
+
+// Constants based on Ethereum research.
+//
+// The Merkle Patricia Trie needs one full path per access, so its witness
+// cost is genuinely linear in the number of distinct accesses. Verkle
+// batches every opening in a block into a single IPA multiproof instead, so
+// its cost is modeled separately below (see `VERKLE_*` multiproof
+// constants) rather than as a flat per-access price.
 const MERKLE_ACCOUNT_WITNESS: usize = 3_000;  // bytes per account in MPT
-const VERKLE_ACCOUNT_WITNESS: usize = 200;    // bytes per account in Verkle
 const MERKLE_STORAGE_WITNESS: usize = 3_000;  // bytes per storage slot in MPT
-const VERKLE_STORAGE_WITNESS: usize = 200;    // bytes per storage slot in Verkle
-const MERKLE_CODE_CHUNK: usize = 24_200;      // bytes for contract code in MPT
-const VERKLE_CODE_CHUNK: usize = 200;         // bytes per code chunk in Verkle (chunked)
+const MERKLE_CODE_CHUNK: usize = 24_200;      // bytes for the whole code trie in MPT, charged once per contract
+
+// EIP-4762: contract code is split into 31-byte chunks, each stored as a
+// 32-byte leaf (1 metadata byte + 31 code bytes).
+const VERKLE_CODE_CHUNK_BYTES: usize = 31;
+
+// Verkle multiproof sizing: a single IPA proof covers every opening in the
+// block, so the witness is a fixed overhead plus a small per-stem and
+// per-leaf cost rather than a flat price per access.
+const VERKLE_IPA_PROOF_OVERHEAD_BYTES: usize = 576; // paid once per block, regardless of opening count
+const VERKLE_COMMITMENT_BYTES: usize = 32;          // per distinct internal commitment node (stem) touched
+const VERKLE_SUFFIX_BYTES: usize = 32;              // per distinct leaf (suffix) touched
+const VERKLE_STEM_WIDTH: usize = 256;               // keys sharing a stem/internal node (EIP-4762 node width)
 
 const BLOCK_TIME_SECONDS: u64 = 12;
 const NETWORK_BANDWIDTH_MBPS: u64 = 10; // Conservative estimate
 
+const DEFAULT_GAS_LIMIT: u64 = 15_000_000;
+const DEFAULT_GAS_PER_ACCESS: u64 = 2_500; // rough cost of a cold SLOAD/account access
+
+/// Tunable network, gas, and witness-size parameters. Defaults match the
+/// constants above; override individual fields (e.g. from CLI flags) to
+/// evaluate different network conditions or pricing assumptions without
+/// editing the source.
+#[derive(Debug, Clone)]
+pub(crate) struct Config {
+    pub(crate) block_time_seconds: u64,
+    pub(crate) network_bandwidth_mbps: u64,
+    pub(crate) gas_per_access: u64,
+    pub(crate) merkle_account_witness: usize,
+    pub(crate) merkle_storage_witness: usize,
+    pub(crate) merkle_code_chunk: usize,
+    pub(crate) verkle_ipa_proof_overhead_bytes: usize,
+    pub(crate) verkle_commitment_bytes: usize,
+    pub(crate) verkle_suffix_bytes: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            block_time_seconds: BLOCK_TIME_SECONDS,
+            network_bandwidth_mbps: NETWORK_BANDWIDTH_MBPS,
+            gas_per_access: DEFAULT_GAS_PER_ACCESS,
+            merkle_account_witness: MERKLE_ACCOUNT_WITNESS,
+            merkle_storage_witness: MERKLE_STORAGE_WITNESS,
+            merkle_code_chunk: MERKLE_CODE_CHUNK,
+            verkle_ipa_proof_overhead_bytes: VERKLE_IPA_PROOF_OVERHEAD_BYTES,
+            verkle_commitment_bytes: VERKLE_COMMITMENT_BYTES,
+            verkle_suffix_bytes: VERKLE_SUFFIX_BYTES,
+        }
+    }
+}
+
+impl Config {
+    /// Maximum witness size, in bytes, that can propagate within one block
+    /// slot at this config's assumed network bandwidth.
+    fn max_propagatable_bytes(&self) -> usize {
+        let bits_per_slot = self
+            .network_bandwidth_mbps
+            .saturating_mul(1_000_000)
+            .saturating_mul(self.block_time_seconds);
+        (bits_per_slot / 8) as usize
+    }
+
+    /// Worst-case number of state accesses a block could contain, derived
+    /// from a gas limit and this config's assumed gas cost per access.
+    /// Returns 0 if `gas_per_access` is 0 rather than dividing by zero.
+    fn worst_case_access_count(&self, gas_limit: u64) -> usize {
+        if self.gas_per_access == 0 {
+            return 0;
+        }
+        (gas_limit / self.gas_per_access) as usize
+    }
+}
+
+/// Address and storage-key representation. Kept as plain hex strings (rather
+/// than a fixed-size byte array) so the same type can later be fed directly
+/// from EIP-2930 access-list JSON without a conversion layer.
+pub(crate) type Address = String;
+pub(crate) type StorageKey = String;
+
+/// A Verkle "stem": the 31-byte internal commitment a group of up to
+/// `VERKLE_STEM_WIDTH` leaves share. Leaves that fall under the same stem
+/// reuse the same internal commitment node in a multiproof, so only the
+/// number of *distinct* stems touched matters for witness size, not the
+/// number of leaves under them.
+#[derive(Hash, Eq, PartialEq, Clone)]
+enum Stem {
+    /// An account's header stem (balance/nonce/codehash/codesize).
+    Header(Address),
+    /// A bucket of `VERKLE_STEM_WIDTH` consecutive storage slots.
+    Storage(Address, u128),
+    /// A bucket of `VERKLE_STEM_WIDTH` consecutive code chunks.
+    Code(Address, u128),
+}
+
+/// Groups a hex-encoded key into its `VERKLE_STEM_WIDTH`-sized stem bucket.
+/// Only the low 128 bits are considered, which is more than enough entropy
+/// for bucketing and keeps the math in a plain `u128`.
+fn stem_bucket(hex_key: &str) -> u128 {
+    let digits = hex_key.trim_start_matches("0x");
+    let start = digits.len().saturating_sub(32);
+    let low_bits = u128::from_str_radix(&digits[start..], 16).unwrap_or(0);
+    low_bits / VERKLE_STEM_WIDTH as u128
+}
+
+/// Tracks which accounts, storage slots, and contract code have already been
+/// witnessed during a scenario, mirroring how EVM runners cache
+/// `AccountBasicRead` / `AddressCodeRead` / `SLOAD` results so that a second
+/// touch of the same target is "warm" and requires no additional proof data.
+pub(crate) struct StateAccessTracker {
+    accessed_accounts: HashSet<Address>,
+    accessed_storage: HashSet<(Address, StorageKey)>,
+    accessed_code: HashSet<Address>,
+    accessed_code_chunks: HashSet<(Address, usize)>,
+    touched_stems: HashSet<Stem>,
+    warm_touches: usize,
+}
+
+impl StateAccessTracker {
+    pub(crate) fn new() -> Self {
+        Self {
+            accessed_accounts: HashSet::new(),
+            accessed_storage: HashSet::new(),
+            accessed_code: HashSet::new(),
+            accessed_code_chunks: HashSet::new(),
+            touched_stems: HashSet::new(),
+            warm_touches: 0,
+        }
+    }
+
+    /// Records an account access. Returns `true` if this is the first
+    /// (cold) touch of `address`, `false` if it was already warm.
+    pub(crate) fn access_account(&mut self, address: &Address) -> bool {
+        let cold = self.accessed_accounts.insert(address.clone());
+        self.touched_stems.insert(Stem::Header(address.clone()));
+        if !cold {
+            self.warm_touches += 1;
+        }
+        cold
+    }
+
+    /// Records a storage slot access. Returns `true` on the first (cold)
+    /// touch of `(address, slot)`.
+    pub(crate) fn access_storage(&mut self, address: &Address, slot: &StorageKey) -> bool {
+        let cold = self
+            .accessed_storage
+            .insert((address.clone(), slot.clone()));
+        self.touched_stems
+            .insert(Stem::Storage(address.clone(), stem_bucket(slot)));
+        if !cold {
+            self.warm_touches += 1;
+        }
+        cold
+    }
+
+    /// Records that `address`'s code was read at all. Returns `true` on the
+    /// first (cold) code read of `address` — this is the granularity the
+    /// Merkle side charges at, since the MPT has no notion of code chunks.
+    pub(crate) fn access_code(&mut self, address: &Address) -> bool {
+        let cold = self.accessed_code.insert(address.clone());
+        if !cold {
+            self.warm_touches += 1;
+        }
+        cold
+    }
+
+    /// Records execution of one 31-byte code chunk of `address`, per
+    /// EIP-4762 Verkle code chunking. Returns `true` on the first (cold)
+    /// touch of `(address, chunk_index)`.
+    pub(crate) fn access_code_chunk(&mut self, address: &Address, chunk_index: usize) -> bool {
+        let cold = self
+            .accessed_code_chunks
+            .insert((address.clone(), chunk_index));
+        self.touched_stems.insert(Stem::Code(
+            address.clone(),
+            chunk_index as u128 / VERKLE_STEM_WIDTH as u128,
+        ));
+        if !cold {
+            self.warm_touches += 1;
+        }
+        cold
+    }
+
+    fn cold_accesses(&self) -> usize {
+        self.accessed_accounts.len() + self.accessed_storage.len() + self.accessed_code.len()
+    }
+
+    fn merkle_witness_size(&self, config: &Config) -> usize {
+        self.accessed_accounts.len() * config.merkle_account_witness
+            + self.accessed_storage.len() * config.merkle_storage_witness
+            + self.accessed_code.len() * config.merkle_code_chunk
+    }
+
+    /// A single IPA multiproof covers every opening in the block, so the
+    /// witness is a fixed overhead plus a small cost per distinct internal
+    /// node (stem) and per distinct leaf (suffix) touched — it does not
+    /// grow linearly with the number of accesses the way Merkle does.
+    fn verkle_witness_size(&self, config: &Config) -> usize {
+        let distinct_leaves =
+            self.accessed_accounts.len() + self.accessed_storage.len() + self.accessed_code_chunks.len();
+
+        config.verkle_ipa_proof_overhead_bytes
+            + self.touched_stems.len() * config.verkle_commitment_bytes
+            + distinct_leaves * config.verkle_suffix_bytes
+    }
+}
+
+/// Number of 31-byte Verkle code chunks a contract of `code_len_bytes`
+/// occupies (EIP-4762).
+pub(crate) fn code_chunk_count(code_len_bytes: usize) -> usize {
+    code_len_bytes.div_ceil(VERKLE_CODE_CHUNK_BYTES)
+}
+
 #[derive(Debug)]
-struct WitnessComparison {
+pub(crate) struct WitnessComparison {
     scenario: String,
     merkle_size: usize,
     verkle_size: usize,
+    cold_accesses: usize,
+    warm_accesses: usize,
+    merkle_fits_in_slot: bool,
+    verkle_fits_in_slot: bool,
 }
 
 impl WitnessComparison {
-    fn new(scenario: String, merkle_size: usize, verkle_size: usize) -> Self {
+    /// Builds a comparison from a tracker's deduplicated access set, so
+    /// repeated touches of the same account/slot/code don't inflate the
+    /// witness size. Sizing and the block-propagation check both use
+    /// `config`'s parameters.
+    pub(crate) fn from_tracker(scenario: String, tracker: &StateAccessTracker, config: &Config) -> Self {
+        let merkle_size = tracker.merkle_witness_size(config);
+        let verkle_size = tracker.verkle_witness_size(config);
+        let max_bytes = config.max_propagatable_bytes();
+
         Self {
             scenario,
             merkle_size,
             verkle_size,
+            cold_accesses: tracker.cold_accesses(),
+            warm_accesses: tracker.warm_touches,
+            merkle_fits_in_slot: merkle_size <= max_bytes,
+            verkle_fits_in_slot: verkle_size <= max_bytes,
         }
     }
 
@@ -29,17 +261,34 @@ impl WitnessComparison {
         self.merkle_size as f64 / self.verkle_size as f64
     }
 
-    fn merkle_fits_in_slot(&self) -> bool {
-        let max_bytes = (NETWORK_BANDWIDTH_MBPS * 1_000_000 * BLOCK_TIME_SECONDS) / 8;
-        self.merkle_size <= max_bytes as usize
-    }
+    /// Serializes this comparison as a single-line JSON object, for
+    /// `--format json` / regression tracking and plotting.
+    pub(crate) fn to_json(&self) -> String {
+        let improvement_factor = self.improvement_factor();
+        let improvement_factor_json = if improvement_factor.is_finite() {
+            format!("{improvement_factor:.4}")
+        } else {
+            "null".to_string()
+        };
 
-    fn verkle_fits_in_slot(&self) -> bool {
-        let max_bytes = (NETWORK_BANDWIDTH_MBPS * 1_000_000 * BLOCK_TIME_SECONDS) / 8;
-        self.verkle_size <= max_bytes as usize
+        format!(
+            "{{\"scenario\":\"{}\",\"merkle_size\":{},\"verkle_size\":{},\"cold_accesses\":{},\"warm_accesses\":{},\"improvement_factor\":{},\"merkle_fits_in_slot\":{},\"verkle_fits_in_slot\":{}}}",
+            json_escape(&self.scenario),
+            self.merkle_size,
+            self.verkle_size,
+            self.cold_accesses,
+            self.warm_accesses,
+            improvement_factor_json,
+            self.merkle_fits_in_slot,
+            self.verkle_fits_in_slot,
+        )
     }
 }
 
+fn json_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 fn format_bytes(bytes: usize) -> String {
     if bytes >= 1_000_000 {
         format!("{:.1} MB", bytes as f64 / 1_000_000.0)
@@ -50,96 +299,269 @@ fn format_bytes(bytes: usize) -> String {
     }
 }
 
-fn print_header() {
+fn print_header(config: &Config) {
     println!("\n{}", "=".repeat(70));
     println!("    Ethereum Witness Size Comparison");
     println!("{}", "=".repeat(70));
     println!("\nAnalyzing witness sizes for stateless clients...\n");
     println!("Network assumptions:");
-    println!("  - Block time: {} seconds", BLOCK_TIME_SECONDS);
-    println!("  - Available bandwidth: {} Mbps", NETWORK_BANDWIDTH_MBPS);
+    println!("  - Block time: {} seconds", config.block_time_seconds);
+    println!("  - Available bandwidth: {} Mbps", config.network_bandwidth_mbps);
     println!("{}\n", "=".repeat(70));
 }
 
-fn print_scenario(comparison: &WitnessComparison) {
+fn print_scenario(comparison: &WitnessComparison, config: &Config) {
     println!("\n>>> {}", comparison.scenario);
     println!("{}", "-".repeat(70));
+    println!(
+        "  State accesses:        {:>10} cold, {:>6} warm (cached)",
+        comparison.cold_accesses, comparison.warm_accesses
+    );
     println!("  Merkle Patricia Tree:  {:>15}", format_bytes(comparison.merkle_size));
     println!("  Verkle Tree:           {:>15}", format_bytes(comparison.verkle_size));
     println!("  Improvement:           {:>14.1}x smaller ✓", comparison.improvement_factor());
-    
+
     // For large witnesses, show if they fit in block time
     if comparison.merkle_size > 1_000_000 || comparison.verkle_size > 1_000_000 {
-        println!("\n  Can propagate in {}-second slot:", BLOCK_TIME_SECONDS);
-        println!("    Merkle Patricia Tree: {}", if comparison.merkle_fits_in_slot() { "✓" } else { "✗ (too large!)" });
-        println!("    Verkle Tree:          {}", if comparison.verkle_fits_in_slot() { "✓" } else { "✗" });
+        println!("\n  Can propagate in {}-second slot:", config.block_time_seconds);
+        println!("    Merkle Patricia Tree: {}", if comparison.merkle_fits_in_slot { "✓" } else { "✗ (too large!)" });
+        println!("    Verkle Tree:          {}", if comparison.verkle_fits_in_slot { "✓" } else { "✗" });
     }
 }
 
-fn scenario_single_account() -> WitnessComparison {
-    WitnessComparison::new(
+fn scenario_single_account(config: &Config) -> WitnessComparison {
+    let mut tracker = StateAccessTracker::new();
+    tracker.access_account(&"0xaccount".to_string());
+
+    WitnessComparison::from_tracker(
         "Scenario 1: Single Account Balance Check".to_string(),
-        MERKLE_ACCOUNT_WITNESS,
-        VERKLE_ACCOUNT_WITNESS,
+        &tracker,
+        config,
     )
 }
 
-fn scenario_storage_access(num_slots: usize) -> WitnessComparison {
-    WitnessComparison::new(
-        format!("Scenario 2: Smart Contract Interaction ({} storage slots)", num_slots),
-        MERKLE_STORAGE_WITNESS * num_slots,
-        VERKLE_STORAGE_WITNESS * num_slots,
+fn scenario_storage_access(config: &Config, num_unique_slots: usize, total_touches: usize) -> WitnessComparison {
+    let contract: Address = "0xcontract".to_string();
+    let mut tracker = StateAccessTracker::new();
+    tracker.access_account(&contract);
+
+    // Simulate a loop that re-reads the same handful of slots repeatedly
+    // (e.g. balance/allowance bookkeeping), so later touches are warm.
+    for i in 0..total_touches {
+        let slot = format!("{:#x}", i % num_unique_slots.max(1));
+        tracker.access_storage(&contract, &slot);
+    }
+
+    WitnessComparison::from_tracker(
+        format!(
+            "Scenario 2: Smart Contract Interaction ({} touches over {} slots)",
+            total_touches, num_unique_slots
+        ),
+        &tracker,
+        config,
     )
 }
 
-fn scenario_contract_call_with_code() -> WitnessComparison {
-    // Typical contract call: access account + some storage + code
-    let accounts = 2; // caller and contract
+fn scenario_contract_call_with_code(
+    config: &Config,
+    code_len_bytes: usize,
+    executed_chunk_count: usize,
+) -> WitnessComparison {
+    // Typical contract call: access account + some storage + code. Only the
+    // chunks actually executed are charged on the Verkle side; the Merkle
+    // side pays for the whole code trie regardless of how much code ran.
+    let caller: Address = "0xcaller".to_string();
+    let contract: Address = "0xcontract".to_string();
     let storage_slots = 50;
-    let code_chunks = 1;
-    
-    let merkle_total = (MERKLE_ACCOUNT_WITNESS * accounts) 
-                     + (MERKLE_STORAGE_WITNESS * storage_slots)
-                     + (MERKLE_CODE_CHUNK * code_chunks);
-    
-    let verkle_total = (VERKLE_ACCOUNT_WITNESS * accounts)
-                     + (VERKLE_STORAGE_WITNESS * storage_slots)
-                     + (VERKLE_CODE_CHUNK * code_chunks);
-    
-    WitnessComparison::new(
-        "Scenario 3: Contract Call with Code Access".to_string(),
-        merkle_total,
-        verkle_total,
+    let total_chunks = code_chunk_count(code_len_bytes);
+    let executed_chunks = executed_chunk_count.min(total_chunks);
+
+    let mut tracker = StateAccessTracker::new();
+    tracker.access_account(&caller);
+    tracker.access_account(&contract);
+    for i in 0..storage_slots {
+        let slot = format!("{:#x}", i);
+        tracker.access_storage(&contract, &slot);
+    }
+    tracker.access_code(&contract);
+    for chunk in 0..executed_chunks {
+        tracker.access_code_chunk(&contract, chunk);
+    }
+
+    WitnessComparison::from_tracker(
+        format!(
+            "Scenario 3: Contract Call with Code Access ({} byte contract, {}/{} chunks touched)",
+            code_len_bytes, executed_chunks, total_chunks
+        ),
+        &tracker,
+        config,
     )
 }
 
-fn scenario_full_block() -> WitnessComparison {
-    // Worst case: 15M gas / 2500 gas per access = 6000 accesses
-    // Conservative estimate: 5000 accesses
-    let state_accesses = 5_000;
-    
-    WitnessComparison::new(
-        format!("Scenario 4: Full Block ({} state accesses - worst case)", state_accesses),
-        MERKLE_ACCOUNT_WITNESS * state_accesses,
-        VERKLE_ACCOUNT_WITNESS * state_accesses,
+/// Number of popular contracts a full block is modeled as repeatedly hitting.
+const FULL_BLOCK_HOT_CONTRACTS: usize = 20;
+
+/// Real blocks repeatedly hit a small set of popular contracts (routers,
+/// stablecoins, etc.), each with many distinct storage slots touched across
+/// the block. That's exactly the shape Verkle's multiproof amortizes: a
+/// contract's storage slots pack into `VERKLE_STEM_WIDTH`-sized stems, so
+/// distinct stems grow far slower than distinct leaves as the block's
+/// access count grows — unlike Merkle, which pays per leaf regardless of
+/// stem sharing.
+fn scenario_full_block(config: &Config, total_touches: usize) -> WitnessComparison {
+    let mut tracker = StateAccessTracker::new();
+    for i in 0..total_touches {
+        let contract = format!("0xhot{:x}", i % FULL_BLOCK_HOT_CONTRACTS);
+        tracker.access_account(&contract);
+        let slot = format!("{:#x}", i / FULL_BLOCK_HOT_CONTRACTS);
+        tracker.access_storage(&contract, &slot);
+    }
+
+    WitnessComparison::from_tracker(
+        format!(
+            "Scenario 4: Full Block ({} storage touches across {} hot contracts - worst case)",
+            total_touches, FULL_BLOCK_HOT_CONTRACTS
+        ),
+        &tracker,
+        config,
     )
 }
 
+/// Sweeps block-level access counts, showing how the Verkle multiproof
+/// improvement factor compounds as blocks grow (Merkle scales linearly with
+/// access count; Verkle's fixed IPA overhead is amortized across more
+/// openings).
+fn improvement_factor_curve(config: &Config, access_counts: &[usize]) -> Vec<WitnessComparison> {
+    access_counts
+        .iter()
+        .map(|&count| scenario_full_block(config, count))
+        .collect()
+}
+
+fn print_improvement_factor_curve(curve: &[WitnessComparison]) {
+    println!("\n>>> Scenario 5: Verkle Multiproof Improvement Factor Curve");
+    println!("{}", "-".repeat(70));
+    for comparison in curve {
+        println!(
+            "  Merkle {:>10}  Verkle {:>10}  ->  {:>5.1}x smaller   ({})",
+            format_bytes(comparison.merkle_size),
+            format_bytes(comparison.verkle_size),
+            comparison.improvement_factor(),
+            comparison.scenario
+        );
+    }
+}
+
+/// Looks for `--flag <value>` (or `--flag=value`) among the process
+/// arguments and returns its value, if present.
+fn parse_flag_value(args: &[String], flag: &str) -> Option<String> {
+    for (i, arg) in args.iter().enumerate() {
+        if let Some(value) = arg.strip_prefix(&format!("{flag}=")) {
+            return Some(value.to_string());
+        }
+        if arg == flag {
+            return args.get(i + 1).cloned();
+        }
+    }
+    None
+}
+
+/// Overrides `*target` with `--flag`'s value, parsed as `T`, if the flag was
+/// given and parses cleanly.
+fn apply_flag<T: std::str::FromStr>(args: &[String], flag: &str, target: &mut T) {
+    if let Some(value) = parse_flag_value(args, flag) {
+        match value.parse::<T>() {
+            Ok(parsed) => *target = parsed,
+            Err(_) => eprintln!("Warning: ignoring invalid {flag}={value}"),
+        }
+    }
+}
+
+fn build_config(args: &[String]) -> Config {
+    let mut config = Config::default();
+    apply_flag(args, "--block-time-seconds", &mut config.block_time_seconds);
+    apply_flag(args, "--bandwidth-mbps", &mut config.network_bandwidth_mbps);
+    apply_flag(args, "--gas-per-access", &mut config.gas_per_access);
+    apply_flag(args, "--merkle-account-witness", &mut config.merkle_account_witness);
+    apply_flag(args, "--merkle-storage-witness", &mut config.merkle_storage_witness);
+    apply_flag(args, "--merkle-code-chunk", &mut config.merkle_code_chunk);
+    apply_flag(args, "--verkle-overhead-bytes", &mut config.verkle_ipa_proof_overhead_bytes);
+    apply_flag(args, "--verkle-commitment-bytes", &mut config.verkle_commitment_bytes);
+    apply_flag(args, "--verkle-suffix-bytes", &mut config.verkle_suffix_bytes);
+    config
+}
+
+enum ReportFormat {
+    Text,
+    Json,
+}
+
+/// Renders scenario comparisons and the improvement-factor curve either as
+/// human-readable text or as a machine-readable JSON array (`--format
+/// json`), for regression tracking and plotting across parameter sweeps.
+fn report(config: &Config, scenarios: &[WitnessComparison], curve: &[WitnessComparison], format: &ReportFormat) {
+    match format {
+        ReportFormat::Text => {
+            print_header(config);
+            for comparison in scenarios {
+                print_scenario(comparison, config);
+            }
+            if !curve.is_empty() {
+                print_improvement_factor_curve(curve);
+            }
+            println!("\n{}\n", "=".repeat(70));
+        }
+        ReportFormat::Json => {
+            let json_entries: Vec<String> = scenarios
+                .iter()
+                .chain(curve.iter())
+                .map(WitnessComparison::to_json)
+                .collect();
+            println!("[{}]", json_entries.join(","));
+        }
+    }
+}
+
 fn main() {
-    print_header();
-    
-    // Run scenarios
-    let scenario1 = scenario_single_account();
-    print_scenario(&scenario1);
-    
-    let scenario2 = scenario_storage_access(100);
-    print_scenario(&scenario2);
-    
-    let scenario3 = scenario_contract_call_with_code();
-    print_scenario(&scenario3);
-    
-    let scenario4 = scenario_full_block();
-    print_scenario(&scenario4);
-    
-    println!("\n{}\n", "=".repeat(70));
-}
\ No newline at end of file
+    let args: Vec<String> = std::env::args().collect();
+    let config = build_config(&args);
+    let format = match parse_flag_value(&args, "--format").as_deref() {
+        Some("json") => ReportFormat::Json,
+        _ => ReportFormat::Text,
+    };
+
+    if let Some(path) = parse_flag_value(&args, "--access-list") {
+        match access_list::load_access_list(&path) {
+            Ok(entries) => {
+                let comparison = access_list::witness_comparison_from_access_list(&entries, &config);
+                report(&config, &[comparison], &[], &format);
+            }
+            Err(err) => {
+                eprintln!("Failed to load access list from {path}: {err}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    // Fallback scenarios when no --access-list file is given.
+    let mut gas_limit = DEFAULT_GAS_LIMIT;
+    apply_flag(&args, "--gas-limit", &mut gas_limit);
+    let worst_case_accesses = config.worst_case_access_count(gas_limit);
+
+    let scenarios = vec![
+        scenario_single_account(&config),
+        scenario_storage_access(&config, 20, 100),
+        // A small ERC-20-style contract vs. a large, complex one. Executed
+        // chunk count is scaled with contract size (a call tends to walk a
+        // larger fraction of a bigger contract's logic), so the Verkle side
+        // actually grows with it — unlike Merkle, which pays for the whole
+        // code trie either way.
+        scenario_contract_call_with_code(&config, 3_000, 15),
+        scenario_contract_call_with_code(&config, 24_000, 120),
+        scenario_full_block(&config, worst_case_accesses),
+    ];
+    let curve = improvement_factor_curve(&config, &[100, 1_000, 5_000, 10_000]);
+
+    report(&config, &scenarios, &curve, &format);
+}